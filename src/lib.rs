@@ -0,0 +1,24 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+//! Library of atomically reference-counted pointer kinds behind one interface; no
+//! [`Rc`](alloc::rc::Rc)-backed kind is provided.
+//!
+//! See [`SharedPointer`] for more information.
+
+#![no_std]
+
+extern crate alloc;
+
+mod shared_pointer;
+
+pub use crate::shared_pointer::kind::arc::ArcK;
+pub use crate::shared_pointer::kind::arc_tk::ArcTK;
+pub use crate::shared_pointer::kind::thin_arc::ThinArcK;
+pub use crate::shared_pointer::kind::SharedPointerKind;
+pub use crate::shared_pointer::Borrowed;
+pub use crate::shared_pointer::SharedPointer;
+pub use crate::shared_pointer::SharedSlice;
+pub use crate::shared_pointer::UniqueArc;
@@ -121,6 +121,77 @@ unsafe impl SharedPointerKind for ArcK {
             ptr::drop_in_place::<Arc<T>>(self.as_inner_mut());
         }
     }
+
+    type Borrow<'a, T: 'a> = ArcKBorrow<'a, T>;
+
+    #[inline(always)]
+    unsafe fn borrow<'a, T>(&'a self) -> ArcKBorrow<'a, T> {
+        unsafe { ArcKBorrow(self.as_inner_ref()) }
+    }
+
+    #[inline(always)]
+    unsafe fn upgrade<T>(borrow: &ArcKBorrow<'_, T>) -> ArcK {
+        ArcK::new_from_inner(Arc::clone(borrow.0))
+    }
+
+    #[inline(always)]
+    unsafe fn from_static<T: Clone>(v: &'static T) -> ArcK {
+        // `alloc::sync::Arc` does its own strong/weak bookkeeping in its allocation; there is no
+        // way to point one at `v` without also handing it a real, owned allocation to count
+        // against. So, unlike `ArcTK::from_static`, this still allocates.
+        ArcK::new(v.clone())
+    }
+
+    type Unique<T> = ArcKUnique<T>;
+
+    #[inline(always)]
+    fn new_unique<T>(v: T) -> ArcKUnique<T> {
+        ArcKUnique(Arc::new(v))
+    }
+
+    #[inline(always)]
+    unsafe fn assume_init_unique<T>(unique: ArcKUnique<mem::MaybeUninit<T>>) -> ArcKUnique<T> {
+        // `Arc<MaybeUninit<T>>` and `Arc<T>` share the same allocation layout; `unique` is the
+        // sole owner, so reinterpreting its now-initialized contents in place is sound.
+        unsafe { ArcKUnique(Arc::from_raw(Arc::into_raw(unique.0).cast::<T>())) }
+    }
+
+    #[inline(always)]
+    fn shareable<T>(unique: ArcKUnique<T>) -> ArcK {
+        ArcK::new_from_inner(unique.0)
+    }
+}
+
+/// A borrowed [`ArcK`], see [`SharedPointerKind::borrow`].
+pub struct ArcKBorrow<'a, T>(&'a Arc<T>);
+
+impl<'a, T> Deref for ArcKBorrow<'a, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
+
+/// A uniquely-owned [`ArcK`], see [`SharedPointerKind::new_unique`].
+pub struct ArcKUnique<T>(Arc<T>);
+
+impl<T> Deref for ArcKUnique<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for ArcKUnique<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        // Sound: nothing but this `ArcKUnique` has ever seen this allocation.
+        Arc::get_mut(&mut self.0).expect("ArcKUnique is always exclusively owned")
+    }
 }
 
 impl PartialEq for ArcK {
@@ -138,33 +209,5 @@ impl Debug for ArcK {
     }
 }
 
-#[cfg(feature = "serde")]
-pub mod serde {
-    use serde::{Deserialize, Serialize};
-    use serde::de::{Error, Unexpected};
-    use crate::{ArcK, SharedPointerKind};
-
-    impl Serialize for ArcK {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: serde::Serializer,
-        {
-            serializer.serialize_unit() // Just write nothing
-        }
-    }
-
-    impl<'de> Deserialize<'de> for ArcK {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: serde::Deserializer<'de>,
-        {
-            let _ = <()>::deserialize(deserializer)?; // Expect unit type
-
-            // Fail intentionally: this should never happen
-            Err(D::Error::invalid_type(Unexpected::Unit, &"RcK should not be deserialized"))
-        }
-    }
-}
-
 #[cfg(test)]
 mod test;
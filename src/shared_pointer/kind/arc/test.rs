@@ -0,0 +1,70 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::ArcK;
+use crate::SharedPointer;
+
+#[test]
+fn new_and_deref() {
+    let p: SharedPointer<i32, ArcK> = SharedPointer::new(42);
+
+    assert_eq!(*p, 42);
+}
+
+#[test]
+fn clone_shares_and_counts() {
+    let p: SharedPointer<i32, ArcK> = SharedPointer::new(42);
+    let q = p.clone();
+
+    assert_eq!(SharedPointer::strong_count(&p), 2);
+    assert!(SharedPointer::ptr_eq(&p, &q));
+
+    drop(q);
+
+    assert_eq!(SharedPointer::strong_count(&p), 1);
+}
+
+#[test]
+fn try_unwrap_succeeds_when_unique() {
+    let p: SharedPointer<i32, ArcK> = SharedPointer::new(42);
+
+    assert_eq!(SharedPointer::try_unwrap(p).unwrap(), 42);
+}
+
+#[test]
+fn try_unwrap_fails_when_shared() {
+    let p: SharedPointer<i32, ArcK> = SharedPointer::new(42);
+    let q = p.clone();
+
+    let p = SharedPointer::try_unwrap(p).unwrap_err();
+
+    assert_eq!(*p, 42);
+    drop(q);
+}
+
+#[test]
+fn get_mut_only_when_unique() {
+    let mut p: SharedPointer<i32, ArcK> = SharedPointer::new(42);
+
+    assert!(SharedPointer::get_mut(&mut p).is_some());
+
+    let q = p.clone();
+    let mut p = p;
+
+    assert!(SharedPointer::get_mut(&mut p).is_none());
+    drop(q);
+}
+
+#[test]
+fn make_mut_clones_on_write() {
+    let mut p: SharedPointer<i32, ArcK> = SharedPointer::new(42);
+    let q = p.clone();
+
+    *SharedPointer::make_mut(&mut p) = 7;
+
+    assert_eq!(*p, 7);
+    assert_eq!(*q, 42);
+    assert!(!SharedPointer::ptr_eq(&p, &q));
+}
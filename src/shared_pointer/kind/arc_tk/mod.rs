@@ -0,0 +1,327 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::shared_pointer::kind::refcount::abort;
+use crate::shared_pointer::kind::refcount::MAX_REFCOUNT;
+use crate::shared_pointer::kind::SharedPointerKind;
+use alloc::alloc::alloc;
+use alloc::alloc::dealloc;
+use alloc::alloc::handle_alloc_error;
+use alloc::alloc::Layout;
+use alloc::boxed::Box;
+use core::fmt;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::Deref;
+use core::ops::DerefMut;
+use core::ptr;
+use core::ptr::NonNull;
+use core::sync::atomic::fence;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+
+/// Tag bit stolen from the low end of `ptr` to mark a pointer built by
+/// [`ArcTK::from_static`](SharedPointerKind::from_static): such a pointer, once the tag is masked
+/// off, points directly at the `&'static T` it was built from, not at an `Inner<T>`. `Inner<T>`'s
+/// alignment is always at least that of `AtomicUsize` (>= 2), so real allocations never set this
+/// bit. This only works for a `&'static T` whose own alignment is also >= 2; `from_static` checks
+/// that and falls back to allocating for narrower `T` (e.g. `u8`, `bool`).
+const STATIC_TAG: usize = 1;
+
+#[repr(C)]
+struct Inner<T> {
+    count: AtomicUsize,
+    data: T,
+}
+
+/// [Type constructor](https://en.wikipedia.org/wiki/Type_constructor) for a weak-count-free,
+/// atomically reference-counted pointer.
+///
+/// Unlike [`ArcK`](crate::ArcK), which is backed by [`alloc::sync::Arc`], this kind's allocation
+/// (servo_arc/triomphe-style) carries a single `AtomicUsize` strong count and no weak count, since
+/// [`SharedPointerKind`] never hands out weak references.  This saves one word per allocation and
+/// a handful of atomic read-modify-write operations on the clone/drop hot paths.
+pub struct ArcTK {
+    /// Pointer to the allocation, erased to `Inner<()>` so that `ArcTK` itself does not need to
+    /// be generic over `T`.  `count` is always at the same offset regardless of `T`, so it may be
+    /// read through this erased pointer directly; `data` must only be accessed after casting back
+    /// to `Inner<T>` for the `T` this pointer was constructed with -- unless [`STATIC_TAG`] is
+    /// set, in which case there is no `Inner<T>` at all; see [`ArcTK::from_static`].
+    ptr: NonNull<Inner<()>>,
+}
+
+unsafe impl Send for ArcTK {}
+unsafe impl Sync for ArcTK {}
+
+impl ArcTK {
+    #[inline(always)]
+    fn is_static(&self) -> bool {
+        (self.ptr.as_ptr() as usize) & STATIC_TAG != 0
+    }
+
+    #[inline(always)]
+    fn data_ptr<T>(&self) -> *mut T {
+        unsafe { data_ptr_from_tagged::<T>(self.ptr) }
+    }
+
+    /// The strong count, or [`usize::MAX`] for a [`from_static`](Self::from_static) pointer,
+    /// which has no real counter to read.
+    #[inline(always)]
+    fn load_count(&self, order: Ordering) -> usize {
+        if self.is_static() {
+            usize::MAX
+        } else {
+            unsafe { self.ptr.as_ref().count.load(order) }
+        }
+    }
+
+    fn allocate<T>(data: T) -> NonNull<Inner<()>> {
+        unsafe {
+            let layout = Layout::new::<Inner<T>>();
+            let raw = alloc(layout).cast::<Inner<T>>();
+
+            if raw.is_null() {
+                handle_alloc_error(layout);
+            }
+
+            ptr::write(raw, Inner { count: AtomicUsize::new(1), data });
+
+            NonNull::new_unchecked(raw.cast::<Inner<()>>())
+        }
+    }
+}
+
+#[inline(always)]
+unsafe fn data_ptr_from_tagged<T>(ptr: NonNull<Inner<()>>) -> *mut T {
+    let addr = ptr.as_ptr() as usize;
+
+    if addr & STATIC_TAG != 0 {
+        (addr & !STATIC_TAG) as *mut T
+    } else {
+        // Stay in raw-pointer land throughout: going through `&mut (*ptr).data` here would mint a
+        // transient `&mut T` on every call, including from the read-only `as_ptr`/`deref`, which is
+        // unsound under Stacked/Tree Borrows for shared data (two live `&T`s, or two threads,
+        // reading through the same allocation would each invalidate the other's shared tag).
+        unsafe { ptr::addr_of_mut!((*ptr.as_ptr().cast::<Inner<T>>()).data) }
+    }
+}
+
+unsafe impl SharedPointerKind for ArcTK {
+    #[inline(always)]
+    fn new<T>(v: T) -> ArcTK {
+        ArcTK { ptr: ArcTK::allocate(v) }
+    }
+
+    #[inline(always)]
+    fn from_box<T>(v: Box<T>) -> ArcTK {
+        ArcTK::new(*v)
+    }
+
+    #[inline(always)]
+    unsafe fn as_ptr<T>(&self) -> *const T {
+        self.data_ptr::<T>()
+    }
+
+    #[inline(always)]
+    unsafe fn deref<T>(&self) -> &T {
+        unsafe { &*self.data_ptr::<T>() }
+    }
+
+    #[inline(always)]
+    unsafe fn try_unwrap<T>(self) -> Result<T, ArcTK> {
+        unsafe {
+            if self.load_count(Ordering::Acquire) != 1 {
+                return Err(self);
+            }
+
+            let data = ptr::read(self.data_ptr::<T>());
+            dealloc(self.ptr.as_ptr().cast::<u8>(), Layout::new::<Inner<T>>());
+
+            Ok(data)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn get_mut<T>(&mut self) -> Option<&mut T> {
+        unsafe {
+            if self.load_count(Ordering::Acquire) == 1 {
+                Some(&mut *self.data_ptr::<T>())
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn make_mut<T: Clone>(&mut self) -> &mut T {
+        unsafe {
+            if self.load_count(Ordering::Acquire) != 1 {
+                let cloned = (*self.data_ptr::<T>()).clone();
+
+                self.drop::<T>();
+                self.ptr = ArcTK::allocate(cloned);
+            }
+
+            &mut *self.data_ptr::<T>()
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn strong_count<T>(&self) -> usize {
+        self.load_count(Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    unsafe fn clone<T>(&self) -> ArcTK {
+        unsafe {
+            if self.is_static() {
+                return ArcTK { ptr: self.ptr };
+            }
+
+            let old_count = self.ptr.as_ref().count.fetch_add(1, Ordering::Relaxed);
+
+            if old_count > MAX_REFCOUNT {
+                abort("ArcTK");
+            }
+
+            ArcTK { ptr: self.ptr }
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn drop<T>(&mut self) {
+        unsafe {
+            if self.is_static() {
+                return;
+            }
+
+            if self.ptr.as_ref().count.fetch_sub(1, Ordering::Release) != 1 {
+                return;
+            }
+
+            fence(Ordering::Acquire);
+
+            ptr::drop_in_place::<T>(self.data_ptr::<T>());
+            dealloc(self.ptr.as_ptr().cast::<u8>(), Layout::new::<Inner<T>>());
+        }
+    }
+
+    type Borrow<'a, T: 'a> = ArcTKBorrow<'a, T>;
+
+    #[inline(always)]
+    unsafe fn borrow<'a, T>(&'a self) -> ArcTKBorrow<'a, T> {
+        ArcTKBorrow { ptr: self.ptr, _marker: PhantomData }
+    }
+
+    #[inline(always)]
+    unsafe fn upgrade<T>(borrow: &ArcTKBorrow<'_, T>) -> ArcTK {
+        unsafe { ArcTK { ptr: borrow.ptr }.clone::<T>() }
+    }
+
+    #[inline(always)]
+    unsafe fn from_static<T: Clone>(v: &'static T) -> ArcTK {
+        // Tagging the address only leaves the real data intact if `T`'s alignment keeps the low
+        // bit clear; for narrower types, fall back to an ordinary allocation (`ArcK::from_static`
+        // always takes this path).
+        if mem::align_of::<T>() < 2 {
+            return ArcTK::new(v.clone());
+        }
+
+        let tagged = ((v as *const T as usize) | STATIC_TAG) as *mut Inner<()>;
+
+        ArcTK { ptr: unsafe { NonNull::new_unchecked(tagged) } }
+    }
+
+    type Unique<T> = ArcTKUnique<T>;
+
+    #[inline(always)]
+    fn new_unique<T>(v: T) -> ArcTKUnique<T> {
+        ArcTKUnique { ptr: ArcTK::allocate(v).cast() }
+    }
+
+    #[inline(always)]
+    unsafe fn assume_init_unique<T>(unique: ArcTKUnique<mem::MaybeUninit<T>>) -> ArcTKUnique<T> {
+        let ptr = unique.ptr.cast::<Inner<T>>();
+        mem::forget(unique);
+
+        ArcTKUnique { ptr }
+    }
+
+    #[inline(always)]
+    fn shareable<T>(unique: ArcTKUnique<T>) -> ArcTK {
+        let ptr = unique.ptr.cast::<Inner<()>>();
+        mem::forget(unique);
+
+        ArcTK { ptr }
+    }
+}
+
+/// A borrowed [`ArcTK`], see [`SharedPointerKind::borrow`]. A single [`NonNull`], carrying no
+/// refcount of its own.
+pub struct ArcTKBorrow<'a, T> {
+    ptr: NonNull<Inner<()>>,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Deref for ArcTKBorrow<'a, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        unsafe { &*data_ptr_from_tagged::<T>(self.ptr) }
+    }
+}
+
+/// A uniquely-owned [`ArcTK`] allocation, see [`SharedPointerKind::new_unique`]. Unlike `ArcTK`
+/// itself, the count in its `Inner<T>` is never touched: exclusive ownership is a static
+/// guarantee, not something enforced at runtime.
+pub struct ArcTKUnique<T> {
+    ptr: NonNull<Inner<T>>,
+}
+
+impl<T> Deref for ArcTKUnique<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        unsafe { &self.ptr.as_ref().data }
+    }
+}
+
+impl<T> DerefMut for ArcTKUnique<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut self.ptr.as_mut().data }
+    }
+}
+
+impl<T> Drop for ArcTKUnique<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(&mut self.ptr.as_mut().data);
+            dealloc(self.ptr.as_ptr().cast::<u8>(), Layout::new::<Inner<T>>());
+        }
+    }
+}
+
+impl PartialEq for ArcTK {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+}
+
+impl Eq for ArcTK {}
+
+impl Debug for ArcTK {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("ArcTK")
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,116 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::ArcTK;
+use crate::SharedPointer;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::string::ToString;
+
+#[test]
+fn new_and_deref() {
+    let p: SharedPointer<i32, ArcTK> = SharedPointer::new(42);
+
+    assert_eq!(*p, 42);
+}
+
+#[test]
+fn clone_shares_and_counts() {
+    let p: SharedPointer<i32, ArcTK> = SharedPointer::new(42);
+    let q = p.clone();
+
+    assert_eq!(SharedPointer::strong_count(&p), 2);
+    assert!(SharedPointer::ptr_eq(&p, &q));
+
+    drop(q);
+
+    assert_eq!(SharedPointer::strong_count(&p), 1);
+}
+
+#[test]
+fn try_unwrap_succeeds_when_unique() {
+    let p: SharedPointer<i32, ArcTK> = SharedPointer::new(42);
+
+    assert_eq!(SharedPointer::try_unwrap(p).unwrap(), 42);
+}
+
+#[test]
+fn try_unwrap_fails_when_shared() {
+    let p: SharedPointer<i32, ArcTK> = SharedPointer::new(42);
+    let q = p.clone();
+
+    let p = SharedPointer::try_unwrap(p).unwrap_err();
+
+    assert_eq!(*p, 42);
+    drop(q);
+}
+
+#[test]
+fn get_mut_only_when_unique() {
+    let mut p: SharedPointer<i32, ArcTK> = SharedPointer::new(42);
+
+    assert!(SharedPointer::get_mut(&mut p).is_some());
+
+    let q = p.clone();
+    let mut p = p;
+
+    assert!(SharedPointer::get_mut(&mut p).is_none());
+    drop(q);
+}
+
+#[test]
+fn make_mut_clones_on_write() {
+    let mut p: SharedPointer<i32, ArcTK> = SharedPointer::new(42);
+    let q = p.clone();
+
+    *SharedPointer::make_mut(&mut p) = 7;
+
+    assert_eq!(*p, 7);
+    assert_eq!(*q, 42);
+    assert!(!SharedPointer::ptr_eq(&p, &q));
+}
+
+#[test]
+fn make_mut_copies_the_right_bytes_when_shared() {
+    let mut p: SharedPointer<String, ArcTK> = SharedPointer::new("hello".to_string());
+    let q = p.clone();
+
+    SharedPointer::make_mut(&mut p).push_str(", world");
+
+    // The clone triggered by `make_mut` must copy `q`'s value, not some stale/garbage bytes from
+    // the erased `Inner<()>` allocation, and must leave `q`'s own copy untouched.
+    assert_eq!(*p, "hello, world");
+    assert_eq!(*q, "hello");
+}
+
+#[test]
+fn make_mut_mutates_in_place_when_unique() {
+    let mut p: SharedPointer<i32, ArcTK> = SharedPointer::new(42);
+    let before = SharedPointer::as_ptr(&p);
+
+    *SharedPointer::make_mut(&mut p) = 7;
+
+    // No other handle exists, so `make_mut` must not allocate a fresh copy.
+    assert_eq!(SharedPointer::as_ptr(&p), before);
+    assert_eq!(*p, 7);
+}
+
+#[test]
+fn drop_runs_the_payload_destructor_on_last_ref() {
+    let marker = Rc::new(());
+    let p: SharedPointer<Rc<()>, ArcTK> = SharedPointer::new(marker.clone());
+    let q = p.clone();
+
+    // `q` shares `p`'s allocation rather than cloning the wrapped `Rc<()>` again, so the count
+    // stays at 2 (the local `marker` plus the single copy stored in the shared allocation) until
+    // the last `SharedPointer` is dropped.
+    assert_eq!(Rc::strong_count(&marker), 2);
+
+    drop(p);
+    assert_eq!(Rc::strong_count(&marker), 2);
+
+    drop(q);
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
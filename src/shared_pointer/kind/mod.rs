@@ -0,0 +1,156 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use alloc::boxed::Box;
+use core::mem;
+use core::ops::Deref;
+use core::ops::DerefMut;
+
+pub mod arc;
+pub mod arc_tk;
+mod refcount;
+pub mod thin_arc;
+
+/// A trait for the different kinds of pointers that can back a
+/// [`SharedPointer`](crate::SharedPointer).
+///
+/// This is an internal trait.  You should not need to call its methods directly, nor implement
+/// it yourself unless you are adding a new pointer kind to this crate.
+///
+/// # Safety
+///
+/// Implementations must only ever access the wrapped value through the `T` that was passed into
+/// [`new()`](SharedPointerKind::new) or [`from_box()`](SharedPointerKind::from_box); every other
+/// method on this trait receives that same `T` again and must not be called with a different one.
+pub unsafe trait SharedPointerKind: Sized {
+    /// Constructs a new pointer kind wrapping `v`.
+    fn new<T>(v: T) -> Self;
+
+    /// Constructs a new pointer kind, taking ownership of an already-boxed value.
+    fn from_box<T>(v: Box<T>) -> Self;
+
+    /// Returns a raw pointer to the wrapped value.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct this pointer kind.
+    unsafe fn as_ptr<T>(&self) -> *const T;
+
+    /// Dereferences the pointer kind.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct this pointer kind.
+    unsafe fn deref<T>(&self) -> &T;
+
+    /// Returns the wrapped value if this is the only pointer kind to it, otherwise returns `self`
+    /// back as an [`Err`].
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct this pointer kind.
+    unsafe fn try_unwrap<T>(self) -> Result<T, Self>;
+
+    /// Returns a mutable reference to the wrapped value if this is the only pointer kind to it.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct this pointer kind.
+    unsafe fn get_mut<T>(&mut self) -> Option<&mut T>;
+
+    /// Returns a mutable reference to the wrapped value, cloning it into a fresh allocation first
+    /// if it is shared with other pointer kinds.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct this pointer kind.
+    unsafe fn make_mut<T: Clone>(&mut self) -> &mut T;
+
+    /// Returns the number of pointer kinds that share the wrapped value.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct this pointer kind.
+    unsafe fn strong_count<T>(&self) -> usize;
+
+    /// Clones the pointer kind, sharing the same wrapped value.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct this pointer kind.
+    unsafe fn clone<T>(&self) -> Self;
+
+    /// Drops the pointer kind, freeing the wrapped value if this was the last one pointing to it.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct this pointer kind, and this method must
+    /// not be called more than once for a given pointer kind.
+    unsafe fn drop<T>(&mut self);
+
+    /// A single-word, lifetime-bounded handle that [`Deref`]s to `&T` without performing any
+    /// atomic operation -- see [`borrow()`](SharedPointerKind::borrow).
+    type Borrow<'a, T: 'a>: Deref<Target = T>
+    where
+        Self: 'a;
+
+    /// Borrows the wrapped value without touching the reference count.  Useful for threading a
+    /// shared reference through a recursive walk (e.g. of a HAMT or RRB-tree) without the
+    /// per-node clone/drop overhead of bumping and then decrementing the count.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct this pointer kind.
+    unsafe fn borrow<'a, T>(&'a self) -> Self::Borrow<'a, T>;
+
+    /// Materializes a fully owned pointer kind from a borrow, performing exactly one increment of
+    /// the reference count. Used when a [`borrow()`](SharedPointerKind::borrow)ed reference needs
+    /// to outlive the scope it was borrowed in.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type that was used to construct the pointer kind `borrow` was taken from.
+    unsafe fn upgrade<T>(borrow: &Self::Borrow<'_, T>) -> Self;
+
+    /// Constructs a pointer kind from a `&'static T`. Implementations that can represent this
+    /// without a heap allocation (see `ArcTK`) make the resulting `clone`/`drop` no-ops and
+    /// `strong_count` report a sentinel; implementations that cannot (e.g. because they are a
+    /// thin wrapper around a third-party allocator-backed type) may fall back to cloning `v` into
+    /// a normal allocation, which is why this method requires `T: Clone`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type used for every other call on the resulting pointer kind.
+    unsafe fn from_static<T: Clone>(v: &'static T) -> Self;
+
+    /// A uniquely-owned handle to an allocation compatible with this pointer kind: allocated up
+    /// front like a [`new()`](SharedPointerKind::new)ed pointer, but statically known to have no
+    /// other owners, so it may always be mutated through [`DerefMut`] -- see `UniqueArc`.
+    type Unique<T>: DerefMut<Target = T>;
+
+    /// Allocates a new exclusively-owned handle wrapping `v`.
+    fn new_unique<T>(v: T) -> Self::Unique<T>;
+
+    /// Allocates a new exclusively-owned handle with uninitialized contents, to be filled in
+    /// through [`DerefMut`] and then finished with
+    /// [`assume_init_unique`](SharedPointerKind::assume_init_unique).
+    #[inline(always)]
+    fn new_unique_uninit<T>() -> Self::Unique<mem::MaybeUninit<T>> {
+        Self::new_unique(mem::MaybeUninit::uninit())
+    }
+
+    /// Asserts that a handle returned by
+    /// [`new_unique_uninit`](SharedPointerKind::new_unique_uninit) has had its contents fully
+    /// initialized.
+    ///
+    /// # Safety
+    ///
+    /// The value behind `unique` must have been initialized.
+    unsafe fn assume_init_unique<T>(unique: Self::Unique<mem::MaybeUninit<T>>) -> Self::Unique<T>;
+
+    /// Freezes an exclusively-owned handle into a normal, shareable pointer kind. This performs
+    /// no allocation: the same memory keeps being used once sharing begins.
+    fn shareable<T>(unique: Self::Unique<T>) -> Self;
+}
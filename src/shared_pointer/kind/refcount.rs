@@ -0,0 +1,26 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/// The maximum strong count, mirroring the limit [`alloc::sync::Arc`] uses to guard against
+/// overflow caused by pathological [`mem::forget`](core::mem::forget) abuse.
+pub(crate) const MAX_REFCOUNT: usize = isize::MAX as usize;
+
+/// Aborts the process, even on a `no_std` target where [`std::process::abort`] is unavailable:
+/// panicking a second time while already panicking is defined to abort rather than unwind.
+#[cold]
+#[inline(never)]
+pub(crate) fn abort(kind_name: &str) -> ! {
+    struct PanicOnDrop<'a>(&'a str);
+
+    impl Drop for PanicOnDrop<'_> {
+        fn drop(&mut self) {
+            panic!("{} reference count overflow, aborting", self.0);
+        }
+    }
+
+    let _guard = PanicOnDrop(kind_name);
+
+    panic!("{} reference count overflow, aborting", kind_name);
+}
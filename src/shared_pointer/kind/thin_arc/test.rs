@@ -0,0 +1,91 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::ThinArcK;
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[test]
+fn header_and_slice_round_trip() {
+    let p: ThinArcK<&str, i32> = ThinArcK::from_header_and_iter("header", vec![1, 2, 3].into_iter());
+
+    assert_eq!(*p.header(), "header");
+    assert_eq!(&*p, &[1, 2, 3][..]);
+    assert_eq!(p.len(), 3);
+    assert!(!p.is_empty());
+}
+
+#[test]
+fn empty_slice() {
+    let p: ThinArcK<&str, i32> = ThinArcK::from_header_and_iter("header", Vec::new().into_iter());
+
+    assert_eq!(p.len(), 0);
+    assert!(p.is_empty());
+    assert_eq!(&*p, &[][..]);
+}
+
+#[test]
+fn clone_shares_and_counts() {
+    let p: ThinArcK<&str, i32> = ThinArcK::from_header_and_iter("header", vec![1, 2, 3].into_iter());
+
+    assert_eq!(p.strong_count(), 1);
+
+    let q = p.clone();
+
+    assert_eq!(p.strong_count(), 2);
+
+    drop(q);
+
+    assert_eq!(p.strong_count(), 1);
+}
+
+#[test]
+fn drop_runs_element_destructors() {
+    use alloc::rc::Rc;
+
+    let marker = Rc::new(());
+    let p: ThinArcK<(), Rc<()>> =
+        ThinArcK::from_header_and_iter((), vec![marker.clone(), marker.clone()].into_iter());
+
+    assert_eq!(Rc::strong_count(&marker), 3);
+
+    drop(p);
+
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
+
+#[test]
+fn from_header_and_iter_does_not_leak_when_the_iterator_panics() {
+    extern crate std;
+
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+    use std::panic::AssertUnwindSafe;
+
+    let marker = Rc::new(());
+    let pulled = Cell::new(0u32);
+
+    let iter = vec![marker.clone(), marker.clone()].into_iter().map(|v| {
+        pulled.set(pulled.get() + 1);
+
+        if pulled.get() == 2 {
+            // Second element pulled (first is already written into the allocation): panic
+            // partway through the fill, as a misbehaving `Elem`/iterator might.
+            panic!("boom");
+        }
+
+        v
+    });
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let _: ThinArcK<(), Rc<()>> = ThinArcK::from_header_and_iter((), iter);
+    }));
+
+    assert!(result.is_err());
+
+    // The one element written before the panic must have been dropped by the unwind guard, along
+    // with the allocation itself -- not leaked.
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
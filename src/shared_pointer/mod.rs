@@ -0,0 +1,325 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use alloc::boxed::Box;
+use core::fmt;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use core::mem;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::ops::DerefMut;
+use core::ptr;
+
+pub mod kind;
+
+use crate::shared_pointer::kind::SharedPointerKind;
+
+/// A pointer type that can be backed by different kinds of reference counting (see
+/// [`SharedPointerKind`]).
+pub struct SharedPointer<T, K: SharedPointerKind> {
+    ptr: K,
+    _phantom: PhantomData<T>,
+}
+
+// Mirrors `alloc::sync::Arc`'s own bounds: a pointer kind like `ArcTK` erases `T` entirely, so it
+// is `Send`/`Sync` regardless of `T`, which would otherwise let `PhantomData<T>` alone (requiring
+// only `T: Send` for `Send`, only `T: Sync` for `Sync`) under-constrain sharing a non-`Sync` `T`
+// (e.g. `Cell`) across threads. State the real requirement explicitly instead of relying on the
+// fields' auto-derived bounds.
+unsafe impl<T: Send + Sync, K: SharedPointerKind + Send + Sync> Send for SharedPointer<T, K> {}
+unsafe impl<T: Send + Sync, K: SharedPointerKind + Sync> Sync for SharedPointer<T, K> {}
+
+impl<T, K: SharedPointerKind> SharedPointer<T, K> {
+    #[inline(always)]
+    pub fn new(v: T) -> SharedPointer<T, K> {
+        SharedPointer { ptr: K::new(v), _phantom: PhantomData }
+    }
+
+    #[inline(always)]
+    pub fn from_box(v: Box<T>) -> SharedPointer<T, K> {
+        SharedPointer { ptr: K::from_box(v), _phantom: PhantomData }
+    }
+
+    #[inline(always)]
+    pub fn as_ptr(this: &Self) -> *const T {
+        unsafe { this.ptr.as_ptr() }
+    }
+
+    #[inline(always)]
+    pub fn try_unwrap(this: Self) -> Result<T, Self> {
+        // `this` can't be destructured by value: it implements `Drop`. Read its field out from
+        // behind a `ManuallyDrop` instead, so `this`'s own destructor never runs on it.
+        let this = ManuallyDrop::new(this);
+        let ptr = unsafe { ptr::read(&this.ptr) };
+
+        unsafe { ptr.try_unwrap().map_err(|ptr| SharedPointer { ptr, _phantom: PhantomData }) }
+    }
+
+    #[inline(always)]
+    pub fn get_mut(this: &mut Self) -> Option<&mut T> {
+        unsafe { this.ptr.get_mut() }
+    }
+
+    #[inline(always)]
+    pub fn make_mut(this: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        unsafe { this.ptr.make_mut() }
+    }
+
+    #[inline(always)]
+    pub fn strong_count(this: &Self) -> usize {
+        unsafe { this.ptr.strong_count::<T>() }
+    }
+
+    #[inline(always)]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        SharedPointer::as_ptr(this) == SharedPointer::as_ptr(other)
+    }
+
+    /// Borrows the pointed-to value without touching the reference count.  Useful for passing a
+    /// shared reference through a recursive walk (e.g. of a HAMT or RRB-tree node) without the
+    /// clone/drop overhead of bumping and then decrementing the count.
+    #[inline(always)]
+    pub fn borrow(this: &Self) -> Borrowed<'_, T, K> {
+        Borrowed { inner: unsafe { this.ptr.borrow() } }
+    }
+
+    /// Constructs a `SharedPointer` from a `&'static T`. Pointer kinds that can represent this
+    /// without a heap allocation (e.g. [`ArcTK`](crate::ArcTK)) make the resulting clones/drops
+    /// no-ops; see [`SharedPointerKind::from_static`].
+    #[inline(always)]
+    pub fn from_static(v: &'static T) -> SharedPointer<T, K>
+    where
+        T: Clone,
+    {
+        SharedPointer { ptr: unsafe { K::from_static(v) }, _phantom: PhantomData }
+    }
+}
+
+impl<T, K: SharedPointerKind> Deref for SharedPointer<T, K> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        unsafe { self.ptr.deref() }
+    }
+}
+
+impl<T, K: SharedPointerKind> Clone for SharedPointer<T, K> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        SharedPointer { ptr: unsafe { self.ptr.clone::<T>() }, _phantom: PhantomData }
+    }
+}
+
+impl<T, K: SharedPointerKind> Drop for SharedPointer<T, K> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        unsafe { self.ptr.drop::<T>() }
+    }
+}
+
+impl<T: Debug, K: SharedPointerKind> Debug for SharedPointer<T, K> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        Debug::fmt(self.deref(), f)
+    }
+}
+
+/// A value borrowed from a [`SharedPointer`] via [`SharedPointer::borrow`], without having bumped
+/// its reference count.
+pub struct Borrowed<'a, T: 'a, K: SharedPointerKind + 'a> {
+    inner: K::Borrow<'a, T>,
+}
+
+impl<'a, T: 'a, K: SharedPointerKind> Borrowed<'a, T, K> {
+    /// Materializes a fully owned [`SharedPointer`], performing exactly one increment of the
+    /// reference count. Use this when the borrowed reference needs to outlive the scope it was
+    /// borrowed in.
+    #[inline(always)]
+    pub fn upgrade(&self) -> SharedPointer<T, K> {
+        SharedPointer { ptr: unsafe { K::upgrade(&self.inner) }, _phantom: PhantomData }
+    }
+}
+
+impl<'a, T: 'a, K: SharedPointerKind> Deref for Borrowed<'a, T, K> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+/// A uniquely-owned, heap-allocated value compatible with [`SharedPointerKind`] `K`: allocated up
+/// front, but statically guaranteed to have no other owners, so it can always be mutated through
+/// [`DerefMut`] (unlike [`SharedPointer::make_mut`], which needs `T: Clone` to fall back on when
+/// the value turns out to be shared).
+///
+/// Once mutation is done, [`shareable()`](UniqueArc::shareable) freezes it into a normal
+/// [`SharedPointer`], reusing the same allocation.
+pub struct UniqueArc<T, K: SharedPointerKind> {
+    inner: K::Unique<T>,
+}
+
+impl<T, K: SharedPointerKind> UniqueArc<T, K> {
+    #[inline(always)]
+    pub fn new(v: T) -> UniqueArc<T, K> {
+        UniqueArc { inner: K::new_unique(v) }
+    }
+
+    /// Allocates a `UniqueArc` with uninitialized contents, to be filled in through [`DerefMut`]
+    /// and then finished with [`assume_init`](UniqueArc::assume_init).
+    #[inline(always)]
+    pub fn new_uninit() -> UniqueArc<mem::MaybeUninit<T>, K> {
+        UniqueArc { inner: K::new_unique_uninit() }
+    }
+
+    /// Freezes this handle into a normal, shareable [`SharedPointer`]. No allocation: the same
+    /// memory keeps being used once sharing begins.
+    #[inline(always)]
+    pub fn shareable(this: Self) -> SharedPointer<T, K> {
+        SharedPointer { ptr: K::shareable(this.inner), _phantom: PhantomData }
+    }
+}
+
+impl<T, K: SharedPointerKind> UniqueArc<mem::MaybeUninit<T>, K> {
+    /// Asserts that the contents have been fully initialized, typically through [`DerefMut`]
+    /// after [`UniqueArc::new_uninit`].
+    ///
+    /// # Safety
+    ///
+    /// The value must have been initialized.
+    #[inline(always)]
+    pub unsafe fn assume_init(this: Self) -> UniqueArc<T, K> {
+        UniqueArc { inner: unsafe { K::assume_init_unique(this.inner) } }
+    }
+}
+
+impl<T, K: SharedPointerKind> Deref for UniqueArc<T, K> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T, K: SharedPointerKind> DerefMut for UniqueArc<T, K> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+/// The [`SharedPointer`]-level entry point for [`ThinArcK`](crate::ThinArcK): a reference-counted
+/// handle to a header plus an inline, variable-length slice, for data structures (HAMT/RRB-tree
+/// nodes, say) that want a thin pointer to a trailing slice instead of a second indirection.
+///
+/// `ThinArcK` cannot itself be plugged in as a `SharedPointer<T, ThinArcK<H, Elem>>`: its
+/// constructor takes a header plus an [`ExactSizeIterator`] rather than a single by-value `T`
+/// (see [`ThinArcK`](crate::ThinArcK)'s own docs), and `SharedPointer`'s `Drop` impl needs the
+/// `K: SharedPointerKind` bound to already be declared on the `SharedPointer` struct itself --
+/// Rust does not allow a `Drop` impl to add a bound the type it is implemented for does not also
+/// carry. `SharedSlice` exists for exactly this shape instead, wrapping `ThinArcK` directly and
+/// forwarding to its own (already correct) `Deref`/`Clone`/`Drop`.
+pub struct SharedSlice<H, Elem> {
+    inner: crate::ThinArcK<H, Elem>,
+}
+
+impl<H, Elem> SharedSlice<H, Elem> {
+    /// Builds a `SharedSlice` from a `header` and an [`ExactSizeIterator`] of elements.
+    #[inline(always)]
+    pub fn from_header_and_iter<I>(header: H, iter: I) -> SharedSlice<H, Elem>
+    where
+        I: ExactSizeIterator<Item = Elem>,
+    {
+        SharedSlice { inner: crate::ThinArcK::from_header_and_iter(header, iter) }
+    }
+
+    /// Returns a reference to the header.
+    #[inline(always)]
+    pub fn header(this: &Self) -> &H {
+        this.inner.header()
+    }
+
+    /// Returns the number of `SharedSlice` handles sharing this allocation.
+    #[inline(always)]
+    pub fn strong_count(this: &Self) -> usize {
+        this.inner.strong_count()
+    }
+}
+
+impl<H, Elem> Deref for SharedSlice<H, Elem> {
+    type Target = [Elem];
+
+    #[inline(always)]
+    fn deref(&self) -> &[Elem] {
+        &self.inner
+    }
+}
+
+impl<H, Elem> Clone for SharedSlice<H, Elem> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        SharedSlice { inner: self.inner.clone() }
+    }
+}
+
+impl<H: Debug, Elem: Debug> Debug for SharedSlice<H, Elem> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        Debug::fmt(self.deref(), f)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use crate::shared_pointer::kind::SharedPointerKind;
+    use crate::shared_pointer::SharedPointer;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    impl<T: Serialize, K: SharedPointerKind> Serialize for SharedPointer<T, K> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            T::serialize(self, serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>, K: SharedPointerKind> Deserialize<'de> for SharedPointer<T, K> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            T::deserialize(deserializer).map(SharedPointer::new)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use crate::ArcK;
+        use crate::SharedPointer;
+
+        #[test]
+        fn round_trips_the_pointed_to_value() {
+            let p: SharedPointer<i32, ArcK> = SharedPointer::new(42);
+
+            let json = serde_json::to_string(&p).unwrap();
+            let q: SharedPointer<i32, ArcK> = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(*q, 42);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
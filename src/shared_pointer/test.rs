@@ -0,0 +1,122 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::ArcK;
+use crate::ArcTK;
+use crate::SharedPointer;
+use crate::SharedSlice;
+use crate::UniqueArc;
+use alloc::vec;
+use core::mem::MaybeUninit;
+
+#[test]
+fn shared_slice_header_and_slice_round_trip() {
+    let s: SharedSlice<&str, i32> =
+        SharedSlice::from_header_and_iter("header", vec![1, 2, 3].into_iter());
+
+    assert_eq!(*SharedSlice::header(&s), "header");
+    assert_eq!(&*s, &[1, 2, 3][..]);
+}
+
+#[test]
+fn shared_slice_clone_shares_and_counts() {
+    let s: SharedSlice<&str, i32> =
+        SharedSlice::from_header_and_iter("header", vec![1, 2, 3].into_iter());
+
+    assert_eq!(SharedSlice::strong_count(&s), 1);
+
+    let t = s.clone();
+
+    assert_eq!(SharedSlice::strong_count(&s), 2);
+
+    drop(t);
+
+    assert_eq!(SharedSlice::strong_count(&s), 1);
+}
+
+#[test]
+fn borrow_derefs_without_bumping_the_count() {
+    let p: SharedPointer<i32, ArcK> = SharedPointer::new(42);
+
+    let borrowed = SharedPointer::borrow(&p);
+
+    assert_eq!(*borrowed, 42);
+    assert_eq!(SharedPointer::strong_count(&p), 1);
+}
+
+#[test]
+fn upgrade_materializes_an_owned_pointer() {
+    let p: SharedPointer<i32, ArcK> = SharedPointer::new(42);
+
+    let borrowed = SharedPointer::borrow(&p);
+    let upgraded = borrowed.upgrade();
+
+    assert_eq!(*upgraded, 42);
+    assert!(SharedPointer::ptr_eq(&p, &upgraded));
+    assert_eq!(SharedPointer::strong_count(&p), 2);
+}
+
+#[test]
+fn from_static_derefs_to_the_right_value() {
+    static VALUE: i32 = 42;
+
+    let p: SharedPointer<i32, ArcTK> = SharedPointer::from_static(&VALUE);
+
+    assert_eq!(*p, 42);
+    assert_eq!(SharedPointer::strong_count(&p), usize::MAX);
+}
+
+#[test]
+fn from_static_with_align_one_t() {
+    // Regression test: a `&'static T` whose own alignment is 1 (e.g. `u8`) can legitimately end up
+    // at an odd address, which would collide with `ArcTK`'s tag bit if `from_static` tagged the
+    // pointer instead of falling back to allocating for such `T`. Whichever address the static
+    // ends up at, the dereferenced value must be correct -- this is what caught the original bug
+    // (an odd-addressed element silently derefing to the wrong byte).
+    static PAIR: [u8; 2] = [111, 222];
+
+    let first: SharedPointer<u8, ArcTK> = SharedPointer::from_static(&PAIR[0]);
+    let second: SharedPointer<u8, ArcTK> = SharedPointer::from_static(&PAIR[1]);
+
+    assert_eq!(*first, 111);
+    assert_eq!(*second, 222);
+}
+
+#[test]
+fn unique_arc_is_mutable_in_place() {
+    let mut u: UniqueArc<i32, ArcK> = UniqueArc::new(42);
+
+    *u = 7;
+
+    assert_eq!(*u, 7);
+}
+
+#[test]
+fn unique_arc_new_uninit_then_assume_init() {
+    let mut u: UniqueArc<MaybeUninit<i32>, ArcK> = UniqueArc::new_uninit();
+
+    u.write(42);
+
+    let u = unsafe { UniqueArc::assume_init(u) };
+
+    assert_eq!(*u, 42);
+}
+
+#[test]
+fn unique_arc_shareable_reuses_the_allocation() {
+    let u: UniqueArc<i32, ArcK> = UniqueArc::new(42);
+    let before: *const i32 = &*u;
+
+    let p = UniqueArc::shareable(u);
+
+    assert_eq!(SharedPointer::as_ptr(&p), before);
+    assert_eq!(*p, 42);
+    assert_eq!(SharedPointer::strong_count(&p), 1);
+
+    let q = p.clone();
+
+    assert_eq!(SharedPointer::strong_count(&p), 2);
+    drop(q);
+}